@@ -0,0 +1,173 @@
+use std::{fmt::Debug, future::IntoFuture, ops::Deref};
+
+use anyhow::Result;
+
+use crate::{
+    debug::{ValueDebug, ValueDebugFormat, ValueDebugFormatString},
+    trace::{TraceRawVcs, TraceRawVcsContext},
+    vc::{ResolvedVc, Vc},
+    Upcast, VcRead, VcValueType,
+};
+
+/// A "subtype" (via [`Deref`]) of [`Vc`] that represents a cell allocated in the current task's
+/// local arena, via [`Vc::local_cell`]/`.local_cell()` or the generated `.local_cell()`
+/// constructor on a [value][VcValueType].
+///
+/// Unlike [`ResolvedVc`], a `LocalVc` is cheap to create: it does not allocate a cell in the
+/// persistent, global cell store, and so does not invalidate dependents or pollute the cell
+/// space with intermediate values a task only needed for its own bookkeeping.
+///
+/// The tradeoff is that a `LocalVc` is only valid for the lifetime of the task (and any children
+/// it is passed into) that created it -- it must not escape that scope. For this reason,
+/// `LocalVc` does **not** implement [`NonLocalValue`], and cannot be stored inside a
+/// [`#[turbo_tasks::value]`][macro@crate::value] or returned from a
+/// [`#[turbo_tasks::function]`][macro@crate::function]. Call [`LocalVc::to_resolved`] to
+/// materialize the cell's content into a real, global cell once it genuinely needs to cross a
+/// task boundary.
+///
+/// [`NonLocalValue`]: crate::NonLocalValue
+pub struct LocalVc<T>
+where
+    T: ?Sized,
+{
+    pub(crate) node: Vc<T>,
+}
+
+impl<T> Copy for LocalVc<T> where T: ?Sized {}
+
+impl<T> Clone for LocalVc<T>
+where
+    T: ?Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Deref for LocalVc<T>
+where
+    T: ?Sized,
+{
+    type Target = Vc<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.node
+    }
+}
+
+impl<T> IntoFuture for LocalVc<T>
+where
+    T: VcValueType,
+{
+    type Output = <Vc<T> as IntoFuture>::Output;
+    type IntoFuture = <Vc<T> as IntoFuture>::IntoFuture;
+    fn into_future(self) -> Self::IntoFuture {
+        (*self).into_future()
+    }
+}
+
+impl<T> IntoFuture for &LocalVc<T>
+where
+    T: VcValueType,
+{
+    type Output = <Vc<T> as IntoFuture>::Output;
+    type IntoFuture = <Vc<T> as IntoFuture>::IntoFuture;
+    fn into_future(self) -> Self::IntoFuture {
+        (*self).into_future()
+    }
+}
+
+impl<T> IntoFuture for &mut LocalVc<T>
+where
+    T: VcValueType,
+{
+    type Output = <Vc<T> as IntoFuture>::Output;
+    type IntoFuture = <Vc<T> as IntoFuture>::IntoFuture;
+    fn into_future(self) -> Self::IntoFuture {
+        (*self).into_future()
+    }
+}
+
+impl<T> LocalVc<T>
+where
+    T: VcValueType,
+{
+    // called by the `.local_cell()` method generated by the `#[turbo_tasks::value]` macro, the
+    // same way `.resolved_cell()` calls `ResolvedVc::cell_private` (which itself goes through
+    // `Vc::cell_private`) -- `Vc::local_cell_private` is that constructor's task-local sibling.
+    #[doc(hidden)]
+    pub fn cell_private(inner: <T::Read as VcRead<T>>::Target) -> Self {
+        Self {
+            node: Vc::<T>::local_cell_private(inner),
+        }
+    }
+
+    /// Copies this task-local cell's content into a newly allocated global cell, producing a
+    /// [`ResolvedVc`] that is valid beyond the lifetime of the current task.
+    ///
+    /// This is the same [`Vc::to_resolved`] used to resolve any other [`Vc`]: it materializes the
+    /// local cell's current content into a real, global cell (rather than, say, just re-pointing
+    /// at the same task-local slot), which is what makes the result usable after the current task
+    /// returns.
+    ///
+    /// Prefer keeping intermediate values as a `LocalVc` for as long as possible -- only call
+    /// this once the value genuinely needs to cross a task boundary, e.g. because it is being
+    /// stored in a [`#[turbo_tasks::value]`][macro@crate::value] or returned from the task.
+    pub async fn to_resolved(self) -> Result<ResolvedVc<T>> {
+        self.node.to_resolved().await
+    }
+}
+
+impl<T> LocalVc<T>
+where
+    T: ?Sized,
+{
+    /// Upcasts the given `LocalVc<T>` to a `LocalVc<Box<dyn K>>`.
+    ///
+    /// See also: [`Vc::upcast`].
+    #[inline(always)]
+    pub fn upcast<K>(this: Self) -> LocalVc<K>
+    where
+        T: Upcast<K>,
+        K: crate::VcValueTrait + ?Sized,
+    {
+        LocalVc {
+            node: Vc::upcast(this.node),
+        }
+    }
+}
+
+/// Generates an opaque debug representation of the [`LocalVc`] itself, but not the data inside
+/// of it.
+///
+/// This is implemented to allow types containing [`LocalVc`] to implement the synchronous
+/// [`Debug`] trait, but in most cases users should use the [`ValueDebug`] implementation to get a
+/// string representation of the contents of the cell.
+impl<T> Debug for LocalVc<T>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalVc")
+            .field("node", &self.node.node)
+            .finish()
+    }
+}
+
+impl<T> TraceRawVcs for LocalVc<T>
+where
+    T: ?Sized,
+{
+    fn trace_raw_vcs(&self, trace_context: &mut TraceRawVcsContext) {
+        TraceRawVcs::trace_raw_vcs(&self.node, trace_context);
+    }
+}
+
+impl<T> ValueDebugFormat for LocalVc<T>
+where
+    T: Upcast<Box<dyn ValueDebug>> + Send + Sync + ?Sized,
+{
+    fn value_debug_format(&self, depth: usize) -> ValueDebugFormatString {
+        self.node.value_debug_format(depth)
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     any::Any,
+    cmp::Ordering,
     fmt::Debug,
     future::IntoFuture,
     hash::{Hash, Hasher},
@@ -14,7 +15,8 @@ use crate::{
     debug::{ValueDebug, ValueDebugFormat, ValueDebugFormatString},
     trace::{TraceRawVcs, TraceRawVcsContext},
     vc::Vc,
-    ResolveTypeError, Upcast, VcRead, VcTransparentRead, VcValueTrait, VcValueType,
+    RawVc, ReadConsistency, ReadRef, ResolveTypeError, TaskId, Upcast, VcRead, VcTransparentRead,
+    VcValueTrait, VcValueType,
 };
 
 /// A "subtype" (via [`Deref`]) of [`Vc`] that represents a specific [`Vc::cell`]/`.cell()` or
@@ -132,6 +134,40 @@ where
     }
 }
 
+impl<T> PartialOrd for ResolvedVc<T>
+where
+    T: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for ResolvedVc<T>
+where
+    T: ?Sized,
+{
+    /// Orders `ResolvedVc`s by the identity of their backing `RawVc::TaskCell`, without reading
+    /// the cell's contents.
+    ///
+    /// A `ResolvedVc` always points at a concrete `TaskCell`, so this is a plain tuple
+    /// comparison of `(TaskId, CellId's type id, CellId's index)`. Two `ResolvedVc`s that are
+    /// `Eq` always compare `Ordering::Equal`, keeping this consistent with the `Hash`
+    /// implementation above.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let RawVc::TaskCell(self_task_id, self_cell_id) = self.node.node else {
+            unreachable!("ResolvedVc always points to a TaskCell");
+        };
+        let RawVc::TaskCell(other_task_id, other_cell_id) = other.node.node else {
+            unreachable!("ResolvedVc always points to a TaskCell");
+        };
+        self_task_id
+            .cmp(&other_task_id)
+            .then_with(|| self_cell_id.type_id.cmp(&other_cell_id.type_id))
+            .then_with(|| self_cell_id.index.cmp(&other_cell_id.index))
+    }
+}
+
 impl<T, Inner, Repr> Default for ResolvedVc<T>
 where
     T: VcValueType<Read = VcTransparentRead<T, Inner, Repr>>,
@@ -173,6 +209,36 @@ where
             node: Vc::<T>::cell_private(inner),
         }
     }
+
+    /// Synchronously reads the value contained in this cell, but only if the cell is already
+    /// populated.
+    ///
+    /// Because a `ResolvedVc` always points at a single, already-resolved cell, reading it does
+    /// not inherently require an `.await`. This is a best-effort, non-blocking alternative to
+    /// awaiting the `ResolvedVc`: it returns `None` if the cell's content is not currently
+    /// available, in which case the caller should fall back to `.await`ing `this` as normal.
+    pub fn try_read_sync(this: Self) -> Option<ReadRef<T>> {
+        let raw_vc = this.node.node;
+        let RawVc::TaskCell(task_id, cell_id) = raw_vc else {
+            unreachable!("ResolvedVc always points to a TaskCell");
+        };
+
+        // `try_read_task_cell_untracked` returns `Ok(Ok(content))` once the cell is populated,
+        // `Ok(Err(_))` with a listener to await if the task is still computing it, and `Err(_)`
+        // if the read itself failed. Only the first case lets us avoid the scheduler; the other
+        // two fall back to the caller `.await`ing `this` as normal.
+        let Ok(Ok(content)) = crate::turbo_tasks().try_read_task_cell_untracked(
+            task_id,
+            cell_id,
+            ReadConsistency::Eventual,
+        ) else {
+            return None;
+        };
+
+        // `TypedCellContent::cast` does the downcast of the stored `SharedReference` to
+        // `<T::Read as VcRead<T>>::Repr`, relying on the memory-layout-equivalence invariant.
+        content.cast::<T>().ok()
+    }
 }
 
 impl<T, Inner, Repr> ResolvedVc<T>
@@ -312,6 +378,51 @@ where
                 },
             })
     }
+
+    /// Attempts to sidecast the given `ResolvedVc<Box<dyn T>>` to a `ResolvedVc<Box<dyn K>>` and
+    /// reads the resulting cell, combining what would otherwise be a
+    /// [`ResolvedVc::try_sidecast`] followed by a separate `.await` into a single awaited call.
+    ///
+    /// Returns `None` if the underlying value type does not implement `K`.
+    ///
+    /// Unlike [`ResolvedVc::cast_ref_type`], this still has to go through the scheduler: a trait
+    /// target's concrete `Repr` is only known once the cell's registered value type has been
+    /// looked up, so there is no synchronous path here the way there is for a value-type target.
+    pub async fn cast_ref<K>(this: Self) -> Result<Option<ReadRef<K>>>
+    where
+        K: Upcast<T> + VcValueTrait + ?Sized,
+    {
+        let Some(sidecast) = Self::try_sidecast_sync(this) else {
+            return Ok(None);
+        };
+        Ok(Some(sidecast.node.await?))
+    }
+
+    /// Attempts to downcast the given `ResolvedVc<Box<dyn T>>` to a `K` (a value type) and reads
+    /// the resulting cell, combining what would otherwise be a [`ResolvedVc::try_downcast_type`]
+    /// followed by a separate `.await` into a single call.
+    ///
+    /// As in [`ResolvedVc::cast_ref`], the type is confirmed synchronously (here with
+    /// `resolved_is_type`). Unlike [`ResolvedVc::cast_ref`], this then first tries
+    /// [`ResolvedVc::try_read_sync`], skipping the scheduler when the cell is already populated.
+    /// The read itself, however, can still land on an
+    /// unpopulated cell (e.g. evicted or still being recomputed), so this falls back to awaiting
+    /// the cell in that case rather than conflating "not synchronously available" with "wrong
+    /// type."
+    ///
+    /// Returns `None` if the underlying value type is not a `K`.
+    pub async fn cast_ref_type<K>(this: Self) -> Result<Option<ReadRef<K>>>
+    where
+        K: Upcast<T> + VcValueType,
+    {
+        let Some(downcast) = Self::try_downcast_type_sync(this) else {
+            return Ok(None);
+        };
+        if let Some(read_ref) = ResolvedVc::try_read_sync(downcast) {
+            return Ok(Some(read_ref));
+        }
+        Ok(Some(downcast.node.await?))
+    }
 }
 
 /// Generates an opaque debug representation of the [`ResolvedVc`] itself, but not the data inside
@@ -348,3 +459,68 @@ where
         self.node.value_debug_format(depth)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CellId;
+
+    /// Builds a `ResolvedVc` pointing directly at a `TaskCell` identity, without going through a
+    /// real backend. Only valid for exercising the synchronous, read-free `Ord`/`PartialOrd`
+    /// impls above.
+    fn resolved_vc_at<T>(task_id: u32, type_id: u32, index: u32) -> ResolvedVc<T>
+    where
+        T: ?Sized,
+    {
+        ResolvedVc {
+            node: Vc {
+                node: RawVc::TaskCell(
+                    TaskId::from(task_id),
+                    CellId {
+                        type_id: type_id.into(),
+                        index: index.into(),
+                    },
+                ),
+                _t: PhantomData,
+            },
+        }
+    }
+
+    #[test]
+    fn eq_implies_equal_ordering() {
+        let a = resolved_vc_at::<()>(1, 1, 0);
+        let b = resolved_vc_at::<()>(1, 1, 0);
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+    }
+
+    #[test]
+    fn orders_by_task_id_then_type_id_then_index() {
+        let lower_task = resolved_vc_at::<()>(1, 5, 9);
+        let higher_task = resolved_vc_at::<()>(2, 0, 0);
+        assert_eq!(lower_task.cmp(&higher_task), Ordering::Less);
+        assert_eq!(higher_task.cmp(&lower_task), Ordering::Greater);
+
+        let lower_type = resolved_vc_at::<()>(1, 5, 9);
+        let higher_type = resolved_vc_at::<()>(1, 6, 0);
+        assert_eq!(lower_type.cmp(&higher_type), Ordering::Less);
+
+        let lower_index = resolved_vc_at::<()>(1, 5, 1);
+        let higher_index = resolved_vc_at::<()>(1, 5, 2);
+        assert_eq!(lower_index.cmp(&higher_index), Ordering::Less);
+    }
+
+    #[test]
+    fn ordering_is_antisymmetric_and_transitive() {
+        let a = resolved_vc_at::<()>(1, 0, 0);
+        let b = resolved_vc_at::<()>(1, 0, 1);
+        let c = resolved_vc_at::<()>(1, 1, 0);
+
+        assert_eq!(a.cmp(&b).reverse(), b.cmp(&a));
+        assert_eq!(b.cmp(&c).reverse(), c.cmp(&b));
+
+        assert!(a < b);
+        assert!(b < c);
+        assert!(a < c);
+    }
+}
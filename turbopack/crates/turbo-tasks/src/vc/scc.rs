@@ -0,0 +1,305 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    backend::TypedCellContent,
+    registry::get_value_type,
+    trace::{TraceRawVcs, TraceRawVcsContext},
+    vc::{ResolvedVc, Vc},
+    RawVc, ReadConsistency, VcValueType,
+};
+
+/// Partitions the graph of `ResolvedVc<T>`s reachable from `roots` into strongly connected
+/// components, using [Tarjan's algorithm].
+///
+/// `roots` -- and every cell reachable from them -- must hold a `T`. Edges are still discovered by
+/// walking the type-erased `RawVc` graph underneath (a cell's value exposes its outgoing `RawVc`s
+/// via the (type-erased, looked up from the value type registry by the cell's stored
+/// `ValueTypeId`) [`TraceRawVcs`] implementation, so the traversal itself doesn't need to know `T`
+/// up front), but each discovered node is downcast back to a `ResolvedVc<T>` before being
+/// returned. If the reachable graph turns out not to be homogeneous, that downcast fails and this
+/// returns an error rather than silently handing back a `ResolvedVc<T>` that doesn't actually
+/// point at a `T`.
+///
+/// Because membership in a strongly connected component must not depend on what happens to be
+/// cached, every reachable cell is required to already be populated: if a cell isn't, this
+/// returns an error rather than silently treating the node as having no outgoing edges.
+///
+/// Each returned inner [`Vec`] is one strongly connected component, in the order Tarjan's
+/// algorithm discovers them (a reverse topological order: a component comes before every
+/// component that references it); singletons (nodes that aren't part of a cycle) are included as
+/// their own one-element component. The traversal and comparisons are entirely synchronous -- no cell
+/// is ever `.await`ed.
+///
+/// [Tarjan's algorithm]: https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm
+pub fn strongly_connected_components<T>(
+    roots: impl IntoIterator<Item = ResolvedVc<T>>,
+) -> Result<Vec<Vec<ResolvedVc<T>>>>
+where
+    T: VcValueType,
+{
+    let raw_roots = roots.into_iter().map(|root| root.node.node);
+    let components = tarjan_scc(raw_roots, children_of)?;
+    components
+        .into_iter()
+        .map(|component| {
+            component
+                .into_iter()
+                .map(typed_resolved_vc)
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect()
+}
+
+/// Reconstructs the `ResolvedVc<T>` a `RawVc` discovered by the type-erased traversal above must
+/// have come from, failing loudly instead of silently mis-typing a node that turns out not to
+/// hold a `T`.
+fn typed_resolved_vc<T>(raw_vc: RawVc) -> Result<ResolvedVc<T>>
+where
+    T: VcValueType,
+{
+    if !raw_vc.resolved_is_type(T::get_value_type_id()) {
+        return Err(anyhow!(
+            "{raw_vc:?} is not a cell of the expected type; strongly_connected_components \
+             requires every cell reachable from `roots` to hold the same type"
+        ));
+    }
+    Ok(ResolvedVc {
+        node: Vc {
+            node: raw_vc,
+            _t: PhantomData,
+        },
+    })
+}
+
+/// Reads `raw_vc`'s cell and returns the `RawVc`s its value's [`TraceRawVcs`] impl enumerates.
+///
+/// Errors if the cell is not yet populated: an SCC computed over a partially-resolved graph would
+/// be meaningless, so we insist the caller resolve everything reachable from their roots first.
+fn children_of(raw_vc: RawVc) -> Result<Vec<RawVc>> {
+    let RawVc::TaskCell(task_id, cell_id) = raw_vc else {
+        unreachable!("strongly_connected_components only visits TaskCells");
+    };
+
+    let TypedCellContent(value_type_id, cell_content) = crate::turbo_tasks()
+        .try_read_task_cell_untracked(task_id, cell_id, ReadConsistency::Eventual)
+        .map_err(|err| anyhow!("failed to read cell for {raw_vc:?}: {err}"))?
+        .map_err(|_| {
+            anyhow!(
+                "cell for {raw_vc:?} is not yet populated; resolve every node reachable from the \
+                 roots before computing strongly connected components"
+            )
+        })?;
+
+    let Some(shared_reference) = cell_content.0 else {
+        return Err(anyhow!(
+            "cell for {raw_vc:?} is empty; resolve every node reachable from the roots before \
+             computing strongly connected components"
+        ));
+    };
+
+    let mut trace_context = TraceRawVcsContext::new();
+    // Every registered value type carries a type-erased `trace_raw_vcs` entry point precisely so
+    // that generic graph walks like this one can enumerate a cell's outgoing `RawVc`s without
+    // knowing its concrete Rust type up front.
+    (get_value_type(value_type_id).trace_raw_vcs)(&shared_reference, &mut trace_context);
+    Ok(trace_context.into_vcs())
+}
+
+/// The actual (iterative) Tarjan's algorithm, kept generic over the node type and the edge
+/// lookup so it can be unit-tested without a running `turbo-tasks` backend.
+fn tarjan_scc<N, F>(roots: impl IntoIterator<Item = N>, mut children_of: F) -> Result<Vec<Vec<N>>>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> Result<Vec<N>>,
+{
+    let mut finder = SccFinder::new();
+    for root in roots {
+        finder.visit(root, &mut children_of)?;
+    }
+    Ok(finder.components)
+}
+
+/// Iterative (stack-based, to avoid overflowing the native stack on deep graphs) state for a
+/// single run of Tarjan's algorithm.
+struct SccFinder<N> {
+    index_counter: usize,
+    index: HashMap<N, usize>,
+    lowlink: HashMap<N, usize>,
+    on_stack: HashSet<N>,
+    stack: Vec<N>,
+    components: Vec<Vec<N>>,
+}
+
+/// One frame of the explicit DFS work stack: a node, its precomputed children, and how many of
+/// those children have already been processed.
+struct Frame<N> {
+    node: N,
+    children: Vec<N>,
+    next_child: usize,
+}
+
+impl<N> SccFinder<N>
+where
+    N: Copy + Eq + Hash,
+{
+    fn new() -> Self {
+        Self {
+            index_counter: 0,
+            index: HashMap::new(),
+            lowlink: HashMap::new(),
+            on_stack: HashSet::new(),
+            stack: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn visit(&mut self, root: N, children_of: &mut impl FnMut(N) -> Result<Vec<N>>) -> Result<()> {
+        if self.index.contains_key(&root) {
+            return Ok(());
+        }
+
+        let mut work: Vec<Frame<N>> = vec![self.push_new_node(root, children_of)?];
+
+        while let Some(frame) = work.last_mut() {
+            if frame.next_child < frame.children.len() {
+                let child = frame.children[frame.next_child];
+                frame.next_child += 1;
+
+                if !self.index.contains_key(&child) {
+                    work.push(self.push_new_node(child, children_of)?);
+                } else if self.on_stack.contains(&child) {
+                    let node = frame.node;
+                    let child_index = self.index[&child];
+                    let lowlink = self.lowlink.get_mut(&node).unwrap();
+                    *lowlink = (*lowlink).min(child_index);
+                }
+                continue;
+            }
+
+            // All children have been visited; finalize this node.
+            let frame = work.pop().unwrap();
+            if let Some(parent) = work.last() {
+                let child_lowlink = self.lowlink[&frame.node];
+                let parent_lowlink = self.lowlink.get_mut(&parent.node).unwrap();
+                *parent_lowlink = (*parent_lowlink).min(child_lowlink);
+            }
+
+            if self.lowlink[&frame.node] == self.index[&frame.node] {
+                let mut component = Vec::new();
+                loop {
+                    let node = self.stack.pop().expect("node must be on the SCC stack");
+                    self.on_stack.remove(&node);
+                    component.push(node);
+                    if node == frame.node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_new_node(
+        &mut self,
+        node: N,
+        children_of: &mut impl FnMut(N) -> Result<Vec<N>>,
+    ) -> Result<Frame<N>> {
+        let index = self.index_counter;
+        self.index_counter += 1;
+        self.index.insert(node, index);
+        self.lowlink.insert(node, index);
+        self.stack.push(node);
+        self.on_stack.insert(node);
+        Ok(Frame {
+            node,
+            children: children_of(node)?,
+            next_child: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::tarjan_scc;
+
+    /// A graph given as an adjacency list of `(n, children)` pairs, looked up by `n`; a node
+    /// without an entry has no outgoing edges.
+    fn scc_of(edges: &[(u32, &[u32])], roots: &[u32]) -> Vec<Vec<u32>> {
+        tarjan_scc(roots.iter().copied(), |n| {
+            Ok(edges
+                .iter()
+                .find(|(node, _)| *node == n)
+                .map(|(_, children)| children.to_vec())
+                .unwrap_or_default())
+        })
+        .unwrap()
+    }
+
+    fn as_sets(components: Vec<Vec<u32>>) -> HashSet<Vec<u32>> {
+        components
+            .into_iter()
+            .map(|mut c| {
+                c.sort_unstable();
+                c
+            })
+            .collect()
+    }
+
+    #[test]
+    fn detects_a_simple_cycle() {
+        // 0 -> 1 -> 2 -> 0, plus an unrelated singleton 3.
+        let edges: &[(u32, &[u32])] = &[
+            (0, &[1]),
+            (1, &[2]),
+            (2, &[0]),
+            (3, &[]),
+        ];
+        let components = as_sets(scc_of(edges, &[0, 3]));
+        assert_eq!(
+            components,
+            HashSet::from([vec![0, 1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn dag_has_only_singleton_components() {
+        // 0 -> 1 -> 2, no cycle.
+        let edges: &[(u32, &[u32])] = &[(0, &[1]), (1, &[2]), (2, &[])];
+        let components = as_sets(scc_of(edges, &[0]));
+        assert_eq!(
+            components,
+            HashSet::from([vec![0], vec![1], vec![2]])
+        );
+    }
+
+    #[test]
+    fn self_loop_is_its_own_component() {
+        let edges: &[(u32, &[u32])] = &[(0, &[0])];
+        let components = scc_of(edges, &[0]);
+        assert_eq!(components, vec![vec![0]]);
+    }
+
+    #[test]
+    fn does_not_revisit_shared_nodes_across_roots() {
+        // Both roots can reach 2, but there's no cycle.
+        let edges: &[(u32, &[u32])] = &[(0, &[2]), (1, &[2]), (2, &[])];
+        let components = scc_of(edges, &[0, 1]);
+        // A revisited node would show up as a second, duplicate component.
+        assert_eq!(components.len(), 3);
+        let components = as_sets(components);
+        assert_eq!(
+            components,
+            HashSet::from([vec![0], vec![1], vec![2]])
+        );
+    }
+}